@@ -26,10 +26,111 @@
 //! `T`), but also partially at runtime (while the `BorrowedCell`
 //! is active, the `LendingCell` behaves as though it is an `Option`
 //! containing `None`.
+//!
+//! Mirroring the aliasing/mutability split that [`std::cell::RefCell`]
+//! draws between `borrow` and `borrow_mut`, a `LendingCell` can also
+//! lend out any number of read-only [`SharedCell`]s at once via
+//! [`LendingCell::to_shared`]. While those are live, the `LendingCell`
+//! still reports a value through `try_get`, but refuses to hand out
+//! a `BorrowedCell` or a `&mut T` until every `SharedCell` is dropped.
+//!
+//! When nothing is lent out and a value is present, a `LendingCell` also
+//! behaves like a [`std::cell::Cell`]: [`LendingCell::replace`],
+//! [`LendingCell::set`], [`LendingCell::take`] and [`LendingCell::swap`]
+//! let you update the contained value in a single call instead of going
+//! through [`LendingCell::get_mut`].
+//!
+//! A `LendingCell` doesn't have to hold a value up front, either. Like
+//! [`std::cell::OnceCell`], [`LendingCell::empty`] creates one with no
+//! value yet, [`LendingCell::is_empty`] reports whether it's still
+//! unfilled, and [`LendingCell::try_init`] fills it exactly once.
+//!
+//! You can also attach a callback with [`LendingCell::on_return`] that
+//! runs the moment a `BorrowedCell` comes home, handy for resetting a
+//! pooled resource without remembering to do so at every call site that
+//! drops the borrow.
+//!
+//! The panicking accessors ([`LendingCell::get`], [`LendingCell::get_mut`],
+//! [`LendingCell::to_borrowed`]) are `#[track_caller]`, so a failed borrow
+//! points at your call site rather than somewhere inside this crate. In
+//! debug builds, the panic also names the source location of the
+//! still-outstanding [`LendingCell::to_borrowed`] call responsible.
 
 use std::cell::UnsafeCell;
 use std::ops::{Deref, DerefMut};
-use std::sync::Arc;
+#[cfg(debug_assertions)]
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Sentinel `state` value meaning the value is lent out exclusively
+/// via a [`BorrowedCell`].
+const EXCLUSIVE: usize = usize::MAX;
+
+/// Callback type registered with [`LendingCell::on_return`].
+type OnReturn<T> = Box<dyn FnMut(&mut T) + Send>;
+
+/// The data shared between a [`LendingCell`] and whatever it has lent out.
+struct Inner<T> {
+    /// `None` until a value is installed into a cell created with
+    /// [`LendingCell::empty`]; otherwise `Some`. [`LendingCell::take`]
+    /// leaves `T::default()` behind, not `None`.
+    value: UnsafeCell<Option<T>>,
+    /// `0` when nothing is lent out, [`EXCLUSIVE`] while a `BorrowedCell`
+    /// is live, otherwise the number of live `SharedCell`s.
+    state: AtomicUsize,
+    /// Paired with `cv` to let [`LendingCell::reclaim_blocking`] and
+    /// [`LendingCell::try_into_inner_blocking`] park until `state` returns
+    /// to `0`. The `Mutex` guards no data of its own; it only exists to
+    /// satisfy `Condvar::wait`.
+    gate: Mutex<()>,
+    cv: Condvar,
+    /// The waker of a pending [`LendingCell::reclaim`] future, if any.
+    #[cfg(feature = "async")]
+    waker: Mutex<Option<std::task::Waker>>,
+    /// Callback set by [`LendingCell::on_return`], run by `BorrowedCell`'s
+    /// `Drop` impl just before the value becomes observable again.
+    on_return: Mutex<Option<OnReturn<T>>>,
+    /// Set to `false` by [`LendingCell`]'s `Drop` impl. Unlike
+    /// `Arc::strong_count`, which is only a racy snapshot, this tells
+    /// `BorrowedCell::drop` unambiguously whether the owner is still
+    /// around to see the `on_return` hook's effect.
+    owner_alive: AtomicBool,
+    /// Where the live [`LendingCell::to_borrowed`] call came from, if any,
+    /// so a later failed borrow can report it. `None` while the value
+    /// isn't exclusively borrowed.
+    #[cfg(debug_assertions)]
+    borrowed_at: Mutex<Option<&'static Location<'static>>>,
+}
+
+impl<T> Inner<T> {
+    /// Wake any thread parked in `reclaim_blocking`/`try_into_inner_blocking`,
+    /// and any task awaiting [`LendingCell::reclaim`]. Called whenever
+    /// `state` transitions back to `0`.
+    fn notify_returned(&self) {
+        let _guard = self.gate.lock().unwrap();
+        self.cv.notify_all();
+        #[cfg(feature = "async")]
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Block the calling thread until `state` is `0`.
+    fn wait_until_free(&self) {
+        if self.state.load(Ordering::Acquire) == 0 {
+            return;
+        }
+        let mut guard = self.gate.lock().unwrap();
+        while self.state.load(Ordering::Acquire) != 0 {
+            guard = self.cv.wait(guard).unwrap();
+        }
+    }
+}
+
+// SAFETY: type imitates T ownership
+unsafe impl<T: Sync> Sync for Inner<T> {}
+unsafe impl<T: Send> Send for Inner<T> {}
 
 /// A container that allows borrowing without lifetimes.
 ///
@@ -43,7 +144,7 @@ use std::sync::Arc;
 /// assert!(lender.try_get().is_some());
 /// ```
 pub struct LendingCell<T> {
-    thing: Arc<UnsafeCell<T>>,
+    inner: Arc<Inner<T>>,
 }
 
 // SAFETY: type imitates T ownership
@@ -53,69 +154,429 @@ unsafe impl<T: Send> Send for LendingCell<T> {}
 impl<T> LendingCell<T> {
     /// Creates a new LendingCell with the given value
     pub fn new(thing: T) -> Self {
+        Self::with_value(Some(thing))
+    }
+
+    /// Creates a new `LendingCell` holding no value yet. Use
+    /// [`LendingCell::try_init`] to fill it; [`LendingCell::set`] and
+    /// [`LendingCell::replace`] panic on an empty cell.
+    pub fn empty() -> Self {
+        Self::with_value(None)
+    }
+
+    fn with_value(value: Option<T>) -> Self {
         Self {
-            thing: Arc::new(UnsafeCell::new(thing)),
+            inner: Arc::new(Inner {
+                value: UnsafeCell::new(value),
+                state: AtomicUsize::new(0),
+                gate: Mutex::new(()),
+                cv: Condvar::new(),
+                #[cfg(feature = "async")]
+                waker: Mutex::new(None),
+                on_return: Mutex::new(None),
+                owner_alive: AtomicBool::new(true),
+                #[cfg(debug_assertions)]
+                borrowed_at: Mutex::new(None),
+            }),
         }
     }
 
-    /// Get a reference to the contained value if it wasn't borrowed with
-    /// [`LendingCell::to_borrowed`]
-    pub fn try_get(&self) -> Option<&T> {
-        if Arc::strong_count(&self.thing) == 1 {
-            Some(unsafe { &*self.thing.get() })
+    /// Panic because `method` couldn't access the value, with as much
+    /// detail as we can afford to track about why.
+    #[cold]
+    #[track_caller]
+    fn panic_unavailable(&self, method: &str) -> ! {
+        let state = self.inner.state.load(Ordering::Acquire);
+        if state == EXCLUSIVE {
+            #[cfg(debug_assertions)]
+            {
+                // Copy the location out and drop the guard before
+                // panicking, so a failed borrow never leaves `borrowed_at`
+                // poisoned for the next one.
+                let loc = *self.inner.borrowed_at.lock().unwrap();
+                if let Some(loc) = loc {
+                    panic!(
+                        "LendingCell::{method} called, but the value is currently lent out \
+                         (borrowed at {loc})"
+                    );
+                }
+            }
+            panic!("LendingCell::{method} called, but the value is currently lent out");
+        }
+        if state != 0 {
+            panic!(
+                "LendingCell::{method} called, but the value is currently lent out to \
+                 {state} SharedCell(s)"
+            );
+        }
+        panic!("LendingCell::{method} called, but the cell is empty");
+    }
+
+    /// Returns `true` if the cell holds no value, i.e. it was created with
+    /// [`LendingCell::empty`] and not yet filled; [`LendingCell::take`]
+    /// leaves `T::default()` behind rather than emptying the cell. Always
+    /// `false` while exclusively borrowed, since a `BorrowedCell` can only
+    /// be created over a present value.
+    pub fn is_empty(&self) -> bool {
+        if self.inner.state.load(Ordering::Acquire) == EXCLUSIVE {
+            false
         } else {
+            unsafe { (*self.inner.value.get()).is_none() }
+        }
+    }
+
+    /// Install `value` into the cell if it is currently empty and isn't
+    /// lent out, otherwise hand `value` back.
+    ///
+    /// Named `try_init` rather than `try_set` to avoid colliding with
+    /// [`LendingCell::try_set`], which overwrites an existing value (and
+    /// fails on an empty cell) instead of only filling an empty cell.
+    pub fn try_init(&mut self, value: T) -> Result<(), T> {
+        if self.inner.state.load(Ordering::Acquire) != 0 || !self.is_empty() {
+            return Err(value);
+        }
+        // SAFETY: `state == 0` means nothing else holds a reference into
+        // `value`, and we have `&mut self`.
+        unsafe {
+            *self.inner.value.get() = Some(value);
+        }
+        Ok(())
+    }
+
+    /// Get a reference to the contained value if one is present and it
+    /// wasn't exclusively borrowed with [`LendingCell::to_borrowed`]. Any
+    /// number of [`SharedCell`]s may be outstanding at the same time,
+    /// since they only permit read-only access.
+    pub fn try_get(&self) -> Option<&T> {
+        if self.inner.state.load(Ordering::Acquire) == EXCLUSIVE {
             None
+        } else {
+            unsafe { (*self.inner.value.get()).as_ref() }
         }
     }
 
     /// Get a reference to the contained value if it wasn't borrowed with
     /// [`LendingCell::to_borrowed`], otherwise panic
+    /// (also if the cell is empty)
+    #[track_caller]
     pub fn get(&self) -> &T {
-        self.try_get().unwrap()
+        match self.try_get() {
+            Some(v) => v,
+            None => self.panic_unavailable("get"),
+        }
     }
 
-    /// Get a mutable reference the contained value if it wasn't borrowed with
-    /// [`LendingCell::to_borrowed`]
+    /// Get a mutable reference the contained value if one is present and
+    /// it isn't currently lent out, either exclusively with
+    /// [`LendingCell::to_borrowed`] or shared with [`LendingCell::to_shared`]
     pub fn try_get_mut(&mut self) -> Option<&mut T> {
-        Arc::get_mut(&mut self.thing).map(|c| c.get_mut())
+        if self.inner.state.load(Ordering::Acquire) == 0 {
+            unsafe { (*self.inner.value.get()).as_mut() }
+        } else {
+            None
+        }
     }
 
     /// Get a mutable reference the contained value if it wasn't borrowed with
     /// [`LendingCell::to_borrowed`], otherwise panic
+    /// (also while any [`SharedCell`] is live, or if the cell is empty)
+    #[track_caller]
     pub fn get_mut(&mut self) -> &mut T {
-        self.try_get_mut().unwrap()
+        if self.inner.state.load(Ordering::Acquire) != 0 {
+            self.panic_unavailable("get_mut");
+        }
+        match unsafe { (*self.inner.value.get()).as_mut() } {
+            Some(v) => v,
+            None => self.panic_unavailable("get_mut"),
+        }
+    }
+
+    /// Replace the contained value with `new`, returning the old value, if
+    /// the cell isn't currently lent out, otherwise return `new` back. Also
+    /// fails (returning `new`) on an empty cell, since there's no old value
+    /// to hand back; use [`LendingCell::try_init`] to fill an empty cell.
+    pub fn try_replace(&mut self, new: T) -> Result<T, T> {
+        match self.try_get_mut() {
+            Some(slot) => Ok(std::mem::replace(slot, new)),
+            None => Err(new),
+        }
+    }
+
+    /// Replace the contained value with `new`, returning the old value, if
+    /// the cell holds a value and isn't currently lent out, otherwise
+    /// panic.
+    pub fn replace(&mut self, new: T) -> T {
+        self.try_replace(new).ok().unwrap()
+    }
+
+    /// Set the contained value to `new`, dropping the old value, if the
+    /// cell holds a value and isn't currently lent out, otherwise return
+    /// `new` back.
+    pub fn try_set(&mut self, new: T) -> Result<(), T> {
+        self.try_replace(new).map(drop)
+    }
+
+    /// Set the contained value to `new`, dropping the old value, if the
+    /// cell holds a value and isn't currently lent out, otherwise panic.
+    pub fn set(&mut self, new: T) {
+        self.replace(new);
+    }
+
+    /// Take the contained value, leaving `T::default()` in its place, if
+    /// the cell holds a value and isn't currently lent out, otherwise
+    /// return `None`.
+    pub fn try_take(&mut self) -> Option<T>
+    where
+        T: Default,
+    {
+        self.try_replace(T::default()).ok()
+    }
+
+    /// Take the contained value, leaving `T::default()` in its place, if
+    /// the cell holds a value and isn't currently lent out, otherwise
+    /// panic.
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.try_take().unwrap()
+    }
+
+    /// Swap the values of two `LendingCell`s, if neither is currently lent
+    /// out, otherwise leave both untouched and return `None`.
+    pub fn try_swap(&mut self, other: &mut LendingCell<T>) -> Option<()> {
+        if self.inner.state.load(Ordering::Acquire) != 0
+            || other.inner.state.load(Ordering::Acquire) != 0
+        {
+            return None;
+        }
+        // SAFETY: both cells were just confirmed un-borrowed, and `self`
+        // and `other` are distinct `LendingCell`s borrowed independently,
+        // so the two pointers never alias.
+        unsafe {
+            std::ptr::swap(self.inner.value.get(), other.inner.value.get());
+        }
+        Some(())
+    }
+
+    /// Swap the values of two `LendingCell`s, if neither is currently lent
+    /// out, otherwise panic.
+    pub fn swap(&mut self, other: &mut LendingCell<T>) {
+        self.try_swap(other).unwrap()
     }
 
     /// Take the contained value and returned it in an owned object if it
     /// isn't already borrowed, otherwise panic.
+    /// Also panics while any [`SharedCell`] is live, or if the cell is empty.
+    #[track_caller]
     pub fn to_borrowed(&mut self) -> BorrowedCell<T> {
-        self.try_to_borrowed().unwrap()
+        match self.try_to_borrowed() {
+            Some(b) => b,
+            None => self.panic_unavailable("to_borrowed"),
+        }
+    }
+
+    /// Register a callback that runs on the value just as an outstanding
+    /// [`BorrowedCell`] is dropped and returned to this cell, before the
+    /// value becomes observable again through `try_get`/`try_get_mut`.
+    /// Replaces any previously registered callback.
+    ///
+    /// The hook does not run if this `LendingCell` is dropped before the
+    /// `BorrowedCell` is returned; in that case the value is simply
+    /// dropped along with it. Calling [`LendingCell::to_borrowed`] (or any
+    /// other method on the owning `LendingCell`) from inside the hook is
+    /// not supported: the value is still marked exclusively borrowed while
+    /// the hook runs, so such a call will observe it as unavailable.
+    pub fn on_return(&mut self, f: impl FnMut(&mut T) + Send + 'static) {
+        *self.inner.on_return.lock().unwrap() = Some(Box::new(f));
     }
 
     /// Take the contained value and returned it in an owned object if it
-    /// isn't already borrowed.
+    /// isn't already borrowed, exclusively or shared, and a value is
+    /// actually present.
+    ///
+    /// In debug builds, this records its caller's location so that a later
+    /// failed borrow can report where the outstanding one came from.
+    #[track_caller]
     pub fn try_to_borrowed(&mut self) -> Option<BorrowedCell<T>> {
-        if Arc::strong_count(&self.thing) == 1 {
+        if self.is_empty() {
+            return None;
+        }
+        if self
+            .inner
+            .state
+            .compare_exchange(0, EXCLUSIVE, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            #[cfg(debug_assertions)]
+            {
+                *self.inner.borrowed_at.lock().unwrap() = Some(Location::caller());
+            }
             Some(BorrowedCell {
-                thing: Arc::clone(&self.thing),
+                inner: Arc::clone(&self.inner),
             })
         } else {
             None
         }
     }
 
-    /// Destroy the container and return the contained object if it isn't
-    /// being borrowed already. If it fails, return myself `LendingCell`
-    pub fn try_into_inner(self) -> Result<T, Self> {
-        Arc::try_unwrap(self.thing)
-            .map(|x| x.into_inner())
-            .map_err(|a| LendingCell { thing: a })
+    /// Lend out a read-only, cloneable handle to the contained value if one
+    /// is present and it isn't already exclusively borrowed, otherwise
+    /// panic.
+    ///
+    /// ```rust
+    /// # use lending_cell::*;
+    /// let mut lender = LendingCell::new("borrowed");
+    /// let shared_a = lender.to_shared();
+    /// let shared_b = shared_a.clone();
+    /// assert_eq!(*lender.try_get().unwrap(), "borrowed"); // reads are still allowed
+    /// assert!(lender.try_get_mut().is_none()); // but no exclusive access
+    /// drop(shared_a);
+    /// drop(shared_b);
+    /// assert!(lender.try_get_mut().is_some());
+    /// ```
+    pub fn to_shared(&mut self) -> SharedCell<T> {
+        self.try_to_shared().unwrap()
+    }
+
+    /// Lend out a read-only, cloneable handle to the contained value if it
+    /// isn't already exclusively borrowed with [`LendingCell::to_borrowed`]
+    /// and a value is actually present. Any number of `SharedCell`s may
+    /// coexist.
+    pub fn try_to_shared(&mut self) -> Option<SharedCell<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        loop {
+            let current = self.inner.state.load(Ordering::Acquire);
+            if current == EXCLUSIVE {
+                return None;
+            }
+            if self
+                .inner
+                .state
+                .compare_exchange_weak(current, current + 1, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(SharedCell {
+                    inner: Arc::clone(&self.inner),
+                });
+            }
+        }
+    }
+
+    /// Destroy the container and return the contained value if it isn't
+    /// being borrowed already, or `Ok(None)` if the cell was empty. If it
+    /// fails because the value is lent out, return myself `LendingCell`.
+    pub fn try_into_inner(self) -> Result<Option<T>, Self> {
+        // `LendingCell` has a `Drop` impl, so `self.inner` can't be moved
+        // out directly; go through `ManuallyDrop` to take it without
+        // running that impl (its only job, marking `owner_alive` false,
+        // would be immediately moot either way: we either consume `inner`
+        // below or hand it right back inside a fresh `LendingCell`).
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: `this.inner` is read exactly once and never touched again.
+        let inner = unsafe { std::ptr::read(&this.inner) };
+        Arc::try_unwrap(inner)
+            .map(|inner| inner.value.into_inner())
+            .map_err(|inner| LendingCell { inner })
+    }
+
+    /// Block the calling thread until every outstanding [`BorrowedCell`]
+    /// and [`SharedCell`] has been returned, then get a mutable reference
+    /// to the contained value, if one is present.
+    ///
+    /// If nothing is currently lent out, this returns immediately.
+    ///
+    /// ```rust
+    /// # use lending_cell::*;
+    /// let mut lender = LendingCell::new(0);
+    /// let borrowed = lender.to_borrowed();
+    /// std::thread::spawn(move || drop(borrowed)).join().unwrap();
+    /// *lender.reclaim_blocking().unwrap() += 1;
+    /// assert_eq!(*lender.get(), 1);
+    /// ```
+    pub fn reclaim_blocking(&mut self) -> Option<&mut T> {
+        self.inner.wait_until_free();
+        unsafe { (*self.inner.value.get()).as_mut() }
+    }
+
+    /// Block the calling thread until every outstanding [`BorrowedCell`]
+    /// and [`SharedCell`] has been returned, then destroy the container
+    /// and return the contained value, or `None` if the cell was empty.
+    pub fn try_into_inner_blocking(self) -> Option<T> {
+        // See `try_into_inner` for why this goes through `ManuallyDrop`.
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: `this.inner` is read exactly once and never touched again.
+        let mut inner = unsafe { std::ptr::read(&this.inner) };
+        loop {
+            inner.wait_until_free();
+            match Arc::try_unwrap(inner) {
+                Ok(inner) => return inner.value.into_inner(),
+                Err(arc) => {
+                    // `state` just reached 0, but the `BorrowedCell`/
+                    // `SharedCell` drop that got us here calls
+                    // `notify_returned()` before its own `Arc<Inner<T>>`
+                    // clone is actually dropped, so we can wake up here
+                    // a moment before that decrement has happened. It's
+                    // always imminent, so just yield and recheck.
+                    inner = arc;
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+
+    /// The `async` counterpart to [`LendingCell::reclaim_blocking`]: waits
+    /// without blocking an executor thread until every outstanding
+    /// [`BorrowedCell`] and [`SharedCell`] has been returned, then yields
+    /// a mutable reference to the contained value, if one is present.
+    #[cfg(feature = "async")]
+    pub fn reclaim(&mut self) -> Reclaim<'_, T> {
+        Reclaim { inner: &self.inner }
+    }
+}
+
+impl<T> Drop for LendingCell<T> {
+    fn drop(&mut self) {
+        // Tell any still-live `BorrowedCell` unambiguously that its owner
+        // is gone, so it knows not to run the `on_return` hook.
+        self.inner.owner_alive.store(false, Ordering::Release);
     }
 }
 
-/// The container that ensures you have borrowed the [`LendingCell`].
+/// Future returned by [`LendingCell::reclaim`].
+#[cfg(feature = "async")]
+pub struct Reclaim<'a, T> {
+    inner: &'a Arc<Inner<T>>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> std::future::Future for Reclaim<'a, T> {
+    type Output = Option<&'a mut T>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if self.inner.state.load(Ordering::Acquire) == 0 {
+            return std::task::Poll::Ready(unsafe { (*self.inner.value.get()).as_mut() });
+        }
+        *self.inner.waker.lock().unwrap() = Some(cx.waker().clone());
+        // Re-check in case the value was returned between the load above
+        // and registering the waker.
+        if self.inner.state.load(Ordering::Acquire) == 0 {
+            return std::task::Poll::Ready(unsafe { (*self.inner.value.get()).as_mut() });
+        }
+        std::task::Poll::Pending
+    }
+}
+
+/// The container that ensures you have exclusively borrowed the
+/// [`LendingCell`]. See also [`SharedCell`] for read-only, aliasable
+/// borrows.
 pub struct BorrowedCell<T> {
-    thing: Arc<UnsafeCell<T>>,
+    inner: Arc<Inner<T>>,
 }
 
 // SAFETY: type imitates either a mutable reference or an ownership
@@ -125,12 +586,325 @@ unsafe impl<T: Sync> Sync for BorrowedCell<T> {}
 impl<T> Deref for BorrowedCell<T> {
     type Target = T;
     fn deref(&self) -> &T {
-        unsafe { &*self.thing.get() }
+        // SAFETY: a `BorrowedCell` can only be created over a present
+        // value (see `try_to_borrowed`), and nothing can empty the slot
+        // while it's exclusively held.
+        unsafe { (*self.inner.value.get()).as_ref().unwrap() }
     }
 }
 
 impl<T> DerefMut for BorrowedCell<T> {
     fn deref_mut(&mut self) -> &mut T {
-        unsafe { &mut *self.thing.get() }
+        unsafe { (*self.inner.value.get()).as_mut().unwrap() }
+    }
+}
+
+impl<T> Drop for BorrowedCell<T> {
+    fn drop(&mut self) {
+        // Run the on-return hook only if the owning `LendingCell` is still
+        // around to see the result; if it already dropped, the value is
+        // about to be destroyed outright.
+        if self.inner.owner_alive.load(Ordering::Acquire) {
+            if let Some(f) = self.inner.on_return.lock().unwrap().as_mut() {
+                // SAFETY: still exclusively held (`state == EXCLUSIVE`),
+                // so no one else can be reading or writing `value`.
+                if let Some(value) = unsafe { (*self.inner.value.get()).as_mut() } {
+                    f(value);
+                }
+            }
+        }
+        #[cfg(debug_assertions)]
+        {
+            *self.inner.borrowed_at.lock().unwrap() = None;
+        }
+        self.inner.state.store(0, Ordering::Release);
+        self.inner.notify_returned();
+    }
+}
+
+/// A read-only, cloneable handle to the value of a [`LendingCell`],
+/// obtained with [`LendingCell::to_shared`]. Any number of `SharedCell`s
+/// may be live at once, but they prevent exclusive access (via
+/// [`LendingCell::to_borrowed`] or [`LendingCell::get_mut`]) until every
+/// one of them is dropped.
+///
+/// `SharedCell<T>` is only `Send` when `T: Sync`, matching `Arc<T>`: two
+/// clones can each hand out a `&T` from different threads at once, so a
+/// `T` that isn't `Sync` (like [`std::cell::Cell`]) must not cross a
+/// thread boundary this way.
+///
+/// ```compile_fail
+/// # use lending_cell::*;
+/// use std::cell::Cell;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<SharedCell<Cell<i32>>>();
+/// ```
+pub struct SharedCell<T> {
+    inner: Arc<Inner<T>>,
+}
+
+// SAFETY: type imitates a shared reference, cloneable across threads, so
+// (like `Arc<T>`) it additionally requires `T: Sync` to be `Send`: two
+// clones can each hand out a `&T` from different threads at once.
+unsafe impl<T: Send + Sync> Send for SharedCell<T> {}
+unsafe impl<T: Sync> Sync for SharedCell<T> {}
+
+impl<T> Deref for SharedCell<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        // SAFETY: a `SharedCell` can only be created over a present value
+        // (see `try_to_shared`), and nothing can empty the slot while any
+        // `SharedCell` is live.
+        unsafe { (*self.inner.value.get()).as_ref().unwrap() }
+    }
+}
+
+impl<T> Clone for SharedCell<T> {
+    fn clone(&self) -> Self {
+        self.inner.state.fetch_add(1, Ordering::AcqRel);
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Drop for SharedCell<T> {
+    fn drop(&mut self) {
+        if self.inner.state.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.inner.notify_returned();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn panic_message_names_the_borrow_call_site() {
+        let mut lender = LendingCell::new(1);
+        let borrowed = lender.to_borrowed(); // <- this call site must show up below
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            lender.get();
+        }));
+        drop(borrowed);
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(
+            message.contains(&format!("borrowed at {}:", file!())),
+            "panic message missing call-site location: {message}"
+        );
+    }
+
+    #[test]
+    fn empty_then_try_init_fills_it_exactly_once() {
+        let mut lender: LendingCell<usize> = LendingCell::empty();
+        assert!(lender.is_empty());
+        assert!(lender.try_get().is_none());
+
+        assert_eq!(lender.try_init(1), Ok(()));
+        assert!(!lender.is_empty());
+        assert_eq!(*lender.get(), 1);
+
+        // Already filled: a second `try_init` hands the value back rather
+        // than overwriting the one that's there.
+        assert_eq!(lender.try_init(2), Err(2));
+        assert_eq!(*lender.get(), 1);
+    }
+
+    #[test]
+    fn take_on_a_filled_cell_leaves_the_default_rather_than_empty() {
+        let mut lender = LendingCell::new(1usize);
+        assert_eq!(lender.take(), 1);
+        // `take` leaves `T::default()` behind, so the cell still reports
+        // a value, not `is_empty()`.
+        assert!(!lender.is_empty());
+        assert_eq!(*lender.get(), 0);
+    }
+
+    #[test]
+    fn try_init_fails_while_borrowed() {
+        let mut lender: LendingCell<usize> = LendingCell::empty();
+        lender.try_init(1).unwrap();
+        let borrowed = lender.to_borrowed();
+        assert_eq!(lender.try_init(2), Err(2));
+        drop(borrowed);
+        assert_eq!(*lender.get(), 1);
+    }
+
+    #[test]
+    fn replace_returns_the_old_value() {
+        let mut lender = LendingCell::new(1);
+        assert_eq!(lender.replace(2), 1);
+        assert_eq!(*lender.get(), 2);
+    }
+
+    #[test]
+    fn set_overwrites_the_value() {
+        let mut lender = LendingCell::new(1);
+        lender.set(2);
+        assert_eq!(*lender.get(), 2);
+    }
+
+    #[test]
+    fn take_leaves_the_default_behind() {
+        let mut lender = LendingCell::new(1);
+        assert_eq!(lender.take(), 1);
+        assert_eq!(*lender.get(), 0);
+    }
+
+    #[test]
+    fn swap_exchanges_values_between_two_cells() {
+        let mut a = LendingCell::new(1);
+        let mut b = LendingCell::new(2);
+        a.swap(&mut b);
+        assert_eq!(*a.get(), 2);
+        assert_eq!(*b.get(), 1);
+    }
+
+    #[test]
+    fn try_replace_try_set_try_take_try_swap_fail_while_borrowed() {
+        let mut lender = LendingCell::new(1);
+        let mut other = LendingCell::new(2);
+        let borrowed = lender.to_borrowed();
+        assert_eq!(lender.try_replace(99), Err(99));
+        assert_eq!(lender.try_set(99), Err(99));
+        assert_eq!(lender.try_take(), None);
+        assert_eq!(lender.try_swap(&mut other), None);
+        drop(borrowed);
+        assert_eq!(*lender.get(), 1);
+        assert_eq!(*other.get(), 2);
+    }
+
+    #[test]
+    fn try_replace_try_set_try_take_fail_while_shared() {
+        let mut lender = LendingCell::new(1);
+        let shared = lender.to_shared();
+        assert_eq!(lender.try_replace(99), Err(99));
+        assert_eq!(lender.try_set(99), Err(99));
+        assert_eq!(lender.try_take(), None);
+        drop(shared);
+        assert_eq!(*lender.get(), 1);
+    }
+
+    #[test]
+    fn try_replace_and_try_take_fail_on_an_empty_cell() {
+        let mut lender: LendingCell<usize> = LendingCell::empty();
+        assert_eq!(lender.try_replace(1), Err(1));
+        assert_eq!(lender.try_take(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn replace_panics_while_borrowed() {
+        let mut lender = LendingCell::new(1);
+        let _borrowed = lender.to_borrowed();
+        lender.replace(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn take_panics_on_an_empty_cell() {
+        let mut lender: LendingCell<usize> = LendingCell::empty();
+        lender.take();
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_panics_while_borrowed() {
+        let mut lender = LendingCell::new(1);
+        let mut other = LendingCell::new(2);
+        let _borrowed = lender.to_borrowed();
+        lender.swap(&mut other);
+    }
+
+    #[test]
+    fn to_borrowed_and_get_mut_are_blocked_while_shared() {
+        let mut lender = LendingCell::new(0usize);
+        let shared = lender.to_shared();
+        assert!(lender.try_to_borrowed().is_none());
+        assert!(lender.try_get_mut().is_none());
+        drop(shared);
+        assert!(lender.try_get_mut().is_some());
+        assert!(lender.try_to_borrowed().is_some());
+    }
+
+    #[test]
+    fn concurrent_shared_cells_from_different_threads_see_the_same_value() {
+        let mut lender = LendingCell::new(42usize);
+        let shared_a = lender.to_shared();
+        let shared_b = shared_a.clone();
+        let handle_a = std::thread::spawn(move || {
+            assert_eq!(*shared_a, 42);
+        });
+        let handle_b = std::thread::spawn(move || {
+            assert_eq!(*shared_b, 42);
+        });
+        handle_a.join().unwrap();
+        handle_b.join().unwrap();
+        // Both `SharedCell`s returned, so exclusive access is available again.
+        assert!(lender.try_to_borrowed().is_some());
+    }
+
+    #[test]
+    fn shared_cell_is_send_when_t_is_send_and_sync() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SharedCell<std::sync::atomic::AtomicUsize>>();
+    }
+
+    // Stress `reclaim_blocking`/`try_into_inner_blocking` against
+    // concurrent `BorrowedCell` drops: the state atomic going back to 0
+    // and the borrower's own `Arc<Inner<T>>` clone actually being dropped
+    // aren't synchronized, so a waiter can wake up a moment before
+    // `Arc::try_unwrap` is guaranteed to succeed.
+    #[test]
+    fn reclaim_blocking_survives_concurrent_borrow_drops() {
+        for _ in 0..200 {
+            let mut lender = LendingCell::new(0usize);
+            let borrowed = lender.to_borrowed();
+            let handle = std::thread::spawn(move || drop(borrowed));
+            assert_eq!(*lender.reclaim_blocking().unwrap(), 0);
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn try_into_inner_blocking_survives_concurrent_borrow_drops() {
+        for _ in 0..200 {
+            let mut lender = LendingCell::new(0usize);
+            let borrowed = lender.to_borrowed();
+            let handle = std::thread::spawn(move || drop(borrowed));
+            assert_eq!(lender.try_into_inner_blocking(), Some(0));
+            handle.join().unwrap();
+        }
+    }
+
+    // `on_return` must fire when the owner is still around to see it...
+    #[test]
+    fn on_return_runs_while_owner_is_alive() {
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut lender = LendingCell::new(0usize);
+        let ran_clone = Arc::clone(&ran);
+        lender.on_return(move |_| {
+            ran_clone.store(true, Ordering::Release);
+        });
+        drop(lender.to_borrowed());
+        assert!(ran.load(Ordering::Acquire));
+    }
+
+    // ...but must not fire if the owner drops first and the
+    // `BorrowedCell` outlives it.
+    #[test]
+    fn on_return_is_skipped_once_owner_has_dropped() {
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut lender = LendingCell::new(0usize);
+        let ran_clone = Arc::clone(&ran);
+        lender.on_return(move |_| {
+            ran_clone.store(true, Ordering::Release);
+        });
+        let borrowed = lender.to_borrowed();
+        drop(lender);
+        drop(borrowed);
+        assert!(!ran.load(Ordering::Acquire));
     }
 }